@@ -1,26 +1,50 @@
 use crate::error::ContractError;
-use crate::responses::AdminListResponse;
-use cosmwasm_std::{Addr, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult};
+use crate::responses::{
+    AdminListResponse, AllPermissionsResponse, PermissionsInfo, PermissionsResponse, RolesResponse,
+};
+use crate::state::{Allowance, Permissions};
+use cosmwasm_std::{
+    Addr, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdError, StdResult, Timestamp,
+};
 
 use cw2::set_contract_version;
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Bound, Item, Map};
+use sha2::{Digest, Sha256};
 use sylvia::contract;
 
+/// Default and maximum page size for the paginated listing queries.
+pub(crate) const DEFAULT_LIMIT: u32 = 10;
+pub(crate) const MAX_LIMIT: u32 = 30;
+
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub struct Cw1WhitelistContract<'a> {
     pub(crate) admins: Map<'static, &'a Addr, Empty>,
     pub(crate) mutable: Item<'static, bool>,
+    pub(crate) allowances: Map<'static, &'a Addr, Allowance>,
+    pub(crate) permissions: Map<'static, &'a Addr, Permissions>,
+    pub(crate) min_delay: Item<'static, u64>,
+    pub(crate) scheduled: Map<'static, &'a [u8], Timestamp>,
+    pub(crate) proposers: Map<'static, &'a Addr, Empty>,
+    pub(crate) executors: Map<'static, &'a Addr, Empty>,
 }
 
 #[contract]
 #[messages(cw1 as Cw1)]
+#[messages(subkeys as Subkeys)]
 impl Cw1WhitelistContract<'_> {
     pub const fn new() -> Self {
         Self {
             admins: Map::new("admins"),
             mutable: Item::new("mutable"),
+            allowances: Map::new("allowances"),
+            permissions: Map::new("permissions"),
+            min_delay: Item::new("min_delay"),
+            scheduled: Map::new("scheduled"),
+            proposers: Map::new("proposers"),
+            executors: Map::new("executors"),
         }
     }
 
@@ -30,6 +54,7 @@ impl Cw1WhitelistContract<'_> {
         ctx: (DepsMut, Env, MessageInfo),
         admins: Vec<String>,
         mutable: bool,
+        min_delay: u64,
     ) -> Result<Response, ContractError> {
         let (deps, _, _) = ctx;
         set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -40,6 +65,7 @@ impl Cw1WhitelistContract<'_> {
         }
 
         self.mutable.save(deps.storage, &mutable)?;
+        self.min_delay.save(deps.storage, &min_delay)?;
 
         Ok(Response::new())
     }
@@ -136,9 +162,326 @@ impl Cw1WhitelistContract<'_> {
         })
     }
 
+    #[msg(exec)]
+    pub fn set_permissions(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        spender: String,
+        permissions: Permissions,
+    ) -> Result<Response, ContractError> {
+        let (deps, _, info) = ctx;
+
+        if !self.is_admin(deps.as_ref(), &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        let spender_addr = deps.api.addr_validate(&spender)?;
+        self.permissions
+            .save(deps.storage, &spender_addr, &permissions)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "set_permissions")
+            .add_attribute("spender", spender);
+        Ok(resp)
+    }
+
+    #[msg(query)]
+    pub fn permissions(&self, ctx: (Deps, Env), spender: String) -> StdResult<PermissionsResponse> {
+        let (deps, _) = ctx;
+        let spender_addr = deps.api.addr_validate(&spender)?;
+        let permissions = self
+            .permissions
+            .may_load(deps.storage, &spender_addr)?
+            .unwrap_or_default();
+        Ok(PermissionsResponse { permissions })
+    }
+
+    #[msg(query)]
+    pub fn all_permissions(
+        &self,
+        ctx: (Deps, Env),
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AllPermissionsResponse> {
+        let (deps, _) = ctx;
+
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after
+            .map(|s| deps.api.addr_validate(&s))
+            .transpose()?;
+        let start = start.as_ref().map(Bound::exclusive);
+
+        let permissions: StdResult<Vec<_>> = self
+            .permissions
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (spender, permissions) = item?;
+                Ok(PermissionsInfo {
+                    spender: spender.into(),
+                    permissions,
+                })
+            })
+            .collect();
+
+        Ok(AllPermissionsResponse {
+            permissions: permissions?,
+        })
+    }
+
+    #[msg(exec)]
+    pub fn schedule(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        msgs: Vec<CosmosMsg>,
+        salt: Binary,
+        delay: u64,
+    ) -> Result<Response, ContractError> {
+        let (deps, env, info) = ctx;
+
+        if !self.is_proposer(deps.as_ref(), &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        let min_delay = self.min_delay.load(deps.storage)?;
+        if delay < min_delay {
+            return Err(ContractError::DelayTooShort { min_delay });
+        }
+
+        let id = operation_id(&msgs, &salt)?;
+        if self.scheduled.has(deps.storage, id.as_slice()) {
+            return Err(ContractError::OperationAlreadyScheduled {});
+        }
+
+        let ready_at = env.block.time.plus_seconds(delay);
+        self.scheduled.save(deps.storage, id.as_slice(), &ready_at)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "schedule")
+            .add_attribute("id", Binary(id).to_base64());
+        Ok(resp)
+    }
+
+    #[msg(exec)]
+    pub fn execute_scheduled(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        msgs: Vec<CosmosMsg>,
+        salt: Binary,
+    ) -> Result<Response, ContractError> {
+        let (deps, env, info) = ctx;
+
+        if !self.is_executor(deps.as_ref(), &info.sender)? {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        let id = operation_id(&msgs, &salt)?;
+        let ready_at = self
+            .scheduled
+            .may_load(deps.storage, id.as_slice())?
+            .ok_or(ContractError::OperationNotScheduled {})?;
+
+        if env.block.time < ready_at {
+            return Err(ContractError::TimelockNotExpired { ready_at });
+        }
+
+        self.scheduled.remove(deps.storage, id.as_slice());
+
+        let resp = Response::new()
+            .add_messages(msgs)
+            .add_attribute("action", "execute_scheduled");
+        Ok(resp)
+    }
+
+    #[msg(exec)]
+    pub fn cancel(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        id: Binary,
+    ) -> Result<Response, ContractError> {
+        let (deps, _, info) = ctx;
+
+        if !self.is_admin(deps.as_ref(), &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        if !self.scheduled.has(deps.storage, id.as_slice()) {
+            return Err(ContractError::OperationNotScheduled {});
+        }
+        self.scheduled.remove(deps.storage, id.as_slice());
+
+        let resp = Response::new()
+            .add_attribute("action", "cancel")
+            .add_attribute("id", id.to_base64());
+        Ok(resp)
+    }
+
+    #[msg(query)]
+    pub fn scheduled_operation(
+        &self,
+        ctx: (Deps, Env),
+        id: Binary,
+    ) -> StdResult<Option<Timestamp>> {
+        let (deps, _) = ctx;
+        self.scheduled.may_load(deps.storage, id.as_slice())
+    }
+
+    #[msg(exec)]
+    pub fn update_proposers(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        proposers: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        let (deps, _, info) = ctx;
+
+        if !self.is_admin(deps.as_ref(), &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+        if !self.mutable.load(deps.storage)? {
+            return Err(ContractError::ContractFrozen {});
+        }
+
+        let existing: Vec<Addr> = self
+            .proposers
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for addr in existing {
+            self.proposers.remove(deps.storage, &addr);
+        }
+        for member in proposers {
+            let addr = deps.api.addr_validate(&member)?;
+            self.proposers.save(deps.storage, &addr, &Empty {})?;
+        }
+
+        let resp = Response::new().add_attribute("action", "update_proposers");
+        Ok(resp)
+    }
+
+    #[msg(exec)]
+    pub fn update_executors(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        executors: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        let (deps, _, info) = ctx;
+
+        if !self.is_admin(deps.as_ref(), &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+        if !self.mutable.load(deps.storage)? {
+            return Err(ContractError::ContractFrozen {});
+        }
+
+        let existing: Vec<Addr> = self
+            .executors
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for addr in existing {
+            self.executors.remove(deps.storage, &addr);
+        }
+        for member in executors {
+            let addr = deps.api.addr_validate(&member)?;
+            self.executors.save(deps.storage, &addr, &Empty {})?;
+        }
+
+        let resp = Response::new().add_attribute("action", "update_executors");
+        Ok(resp)
+    }
+
+    #[msg(query)]
+    pub fn roles(&self, ctx: (Deps, Env)) -> StdResult<RolesResponse> {
+        let (deps, _) = ctx;
+
+        let admins = self
+            .admins
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|addr| addr.map(String::from))
+            .collect::<StdResult<_>>()?;
+        let proposers = self
+            .proposers
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|addr| addr.map(String::from))
+            .collect::<StdResult<_>>()?;
+        let executors = self
+            .executors
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|addr| addr.map(String::from))
+            .collect::<StdResult<_>>()?;
+
+        Ok(RolesResponse {
+            admins,
+            proposers,
+            executors,
+        })
+    }
+
     pub fn is_admin(&self, deps: Deps, addr: &Addr) -> bool {
         self.admins.has(deps.storage, addr)
     }
+
+    /// An address may drive scheduling if it is an admin or an explicit proposer.
+    pub(crate) fn is_proposer(&self, deps: Deps, addr: &Addr) -> bool {
+        self.is_admin(deps, addr) || self.proposers.has(deps.storage, addr)
+    }
+
+    /// An address may trigger a ready operation if it is an admin, an explicit
+    /// executor, or — when the executor set is empty — anyone.
+    pub(crate) fn is_executor(&self, deps: Deps, addr: &Addr) -> StdResult<bool> {
+        if self.is_admin(deps, addr) || self.executors.has(deps.storage, addr) {
+            return Ok(true);
+        }
+        let open = self
+            .executors
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .is_none();
+        Ok(open)
+    }
+
+    /// Loads the permission flags granted to `addr`, defaulting to all-false
+    /// when the address has never been granted any.
+    pub(crate) fn permissions_of(&self, deps: Deps, addr: &Addr) -> StdResult<Permissions> {
+        Ok(self
+            .permissions
+            .may_load(deps.storage, addr)?
+            .unwrap_or_default())
+    }
+}
+
+/// Computes the timelock operation id as the sha2-256 hash of the serialized
+/// messages concatenated with the caller-provided `salt`.
+pub(crate) fn operation_id(msgs: &[CosmosMsg], salt: &[u8]) -> StdResult<Vec<u8>> {
+    let mut hasher = Sha256::new();
+    hasher.update(cosmwasm_std::to_binary(&msgs)?.as_slice());
+    hasher.update(salt);
+    Ok(hasher.finalize().to_vec())
+}
+
+/// Adds `coin` into `balance`, merging with a matching denom if present.
+pub(crate) fn add_coin(balance: &mut Vec<Coin>, coin: Coin) -> Result<(), ContractError> {
+    match balance.iter_mut().find(|c| c.denom == coin.denom) {
+        Some(existing) => {
+            existing.amount = existing
+                .amount
+                .checked_add(coin.amount)
+                .map_err(StdError::overflow)?
+        }
+        None => balance.push(coin),
+    }
+    Ok(())
+}
+
+/// Subtracts `coin` from `balance`, saturating at zero and dropping the denom
+/// once it is fully spent.
+pub(crate) fn sub_coin_saturating(balance: &mut Vec<Coin>, coin: &Coin) {
+    if let Some(pos) = balance.iter().position(|c| c.denom == coin.denom) {
+        let remaining = balance[pos].amount.saturating_sub(coin.amount);
+        if remaining.is_zero() {
+            balance.remove(pos);
+        } else {
+            balance[pos].amount = remaining;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -167,6 +510,7 @@ mod tests {
                 (deps.as_mut(), mock_env(), info),
                 vec![alice.to_string(), bob.to_string(), carl.to_string()],
                 true,
+                0,
             )
             .unwrap();
 
@@ -250,6 +594,7 @@ mod tests {
                 (deps.as_mut(), mock_env(), info),
                 vec![alice.to_string(), carl.to_string()],
                 false,
+                0,
             )
             .unwrap();
 
@@ -268,12 +613,13 @@ mod tests {
             .into(),
         ];
 
-        // bob cannot execute them
+        // bob is neither admin nor proposer, and holds no allowance for the
+        // leading bank send, so he cannot execute them
         let info = mock_info(bob, &[]);
         let err = contract
             .execute((deps.as_mut(), mock_env(), info), msgs.clone())
             .unwrap_err();
-        assert_eq!(err, ContractError::Unauthorized {});
+        assert_eq!(err, ContractError::NoAllowance {});
 
         // but carl can
         let info = mock_info(carl, &[]);
@@ -305,6 +651,7 @@ mod tests {
                 (deps.as_mut(), mock_env(), info),
                 vec![alice.to_string(), bob.to_string()],
                 false,
+                0,
             )
             .unwrap();
 
@@ -351,6 +698,248 @@ mod tests {
         assert!(!res.can_execute);
     }
 
+    #[test]
+    fn permissions_gate_staking_messages() {
+        let mut deps = mock_dependencies();
+
+        let admin = "admin";
+        let spender = "spender";
+
+        let contract = Cw1WhitelistContract::new();
+
+        let info = mock_info(admin, &[]);
+        contract
+            .instantiate(
+                (deps.as_mut(), mock_env(), info),
+                vec![admin.to_string()],
+                true,
+                0,
+            )
+            .unwrap();
+
+        let delegate: CosmosMsg = StakingMsg::Delegate {
+            validator: "valoper".to_string(),
+            amount: coin(1000, "ustake"),
+        }
+        .into();
+
+        // without a permission the spender may not delegate
+        let res = contract
+            .can_execute(
+                (deps.as_ref(), mock_env()),
+                spender.to_string(),
+                delegate.clone(),
+            )
+            .unwrap();
+        assert!(!res.can_execute);
+
+        let info = mock_info(spender, &[]);
+        let err = contract
+            .execute((deps.as_mut(), mock_env(), info), vec![delegate.clone()])
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // grant the delegate permission
+        let info = mock_info(admin, &[]);
+        contract
+            .set_permissions(
+                (deps.as_mut(), mock_env(), info),
+                spender.to_string(),
+                Permissions {
+                    delegate: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let res = contract
+            .can_execute(
+                (deps.as_ref(), mock_env()),
+                spender.to_string(),
+                delegate.clone(),
+            )
+            .unwrap();
+        assert!(res.can_execute);
+
+        let info = mock_info(spender, &[]);
+        let res = contract
+            .execute((deps.as_mut(), mock_env(), info), vec![delegate])
+            .unwrap();
+        assert_eq!(res.attributes, [("action", "execute")]);
+
+        // but other staking categories are still forbidden
+        let undelegate: CosmosMsg = StakingMsg::Undelegate {
+            validator: "valoper".to_string(),
+            amount: coin(1000, "ustake"),
+        }
+        .into();
+        let info = mock_info(spender, &[]);
+        let err = contract
+            .execute((deps.as_mut(), mock_env(), info), vec![undelegate])
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+
+    #[test]
+    fn schedule_and_execute_respects_timelock() {
+        let mut deps = mock_dependencies();
+
+        let admin = "admin";
+        let contract = Cw1WhitelistContract::new();
+
+        let info = mock_info(admin, &[]);
+        contract
+            .instantiate(
+                (deps.as_mut(), mock_env(), info),
+                vec![admin.to_string()],
+                true,
+                100,
+            )
+            .unwrap();
+
+        let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+            to_address: "bob".to_string(),
+            amount: coins(10, "utoken"),
+        }
+        .into()];
+        let salt = Binary(b"salt".to_vec());
+
+        // a delay below the configured minimum is rejected
+        let info = mock_info(admin, &[]);
+        let err = contract
+            .schedule(
+                (deps.as_mut(), mock_env(), info),
+                msgs.clone(),
+                salt.clone(),
+                50,
+            )
+            .unwrap_err();
+        assert_eq!(err, ContractError::DelayTooShort { min_delay: 100 });
+
+        // schedule at exactly the minimum delay
+        let env = mock_env();
+        let ready_at = env.block.time.plus_seconds(100);
+        let info = mock_info(admin, &[]);
+        contract
+            .schedule((deps.as_mut(), env, info), msgs.clone(), salt.clone(), 100)
+            .unwrap();
+
+        let id = operation_id(&msgs, &salt).unwrap();
+        assert_eq!(
+            contract
+                .scheduled_operation((deps.as_ref(), mock_env()), Binary(id.clone()))
+                .unwrap(),
+            Some(ready_at)
+        );
+
+        // scheduling the same operation twice fails
+        let info = mock_info(admin, &[]);
+        let err = contract
+            .schedule(
+                (deps.as_mut(), mock_env(), info),
+                msgs.clone(),
+                salt.clone(),
+                100,
+            )
+            .unwrap_err();
+        assert_eq!(err, ContractError::OperationAlreadyScheduled {});
+
+        // executing before the timelock expires fails
+        let info = mock_info(admin, &[]);
+        let err = contract
+            .execute_scheduled((deps.as_mut(), mock_env(), info), msgs.clone(), salt.clone())
+            .unwrap_err();
+        assert_eq!(err, ContractError::TimelockNotExpired { ready_at });
+
+        // once the timelock expires the messages are dispatched and the entry cleared
+        let mut env = mock_env();
+        env.block.time = ready_at;
+        let info = mock_info(admin, &[]);
+        let res = contract
+            .execute_scheduled((deps.as_mut(), env, info), msgs.clone(), salt.clone())
+            .unwrap();
+        assert_eq!(
+            res.messages,
+            msgs.into_iter().map(SubMsg::new).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            contract
+                .scheduled_operation((deps.as_ref(), mock_env()), Binary(id))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn proposers_and_executors_are_role_gated() {
+        let mut deps = mock_dependencies();
+
+        let admin = "admin";
+        let proposer = "proposer";
+        let contract = Cw1WhitelistContract::new();
+
+        let info = mock_info(admin, &[]);
+        contract
+            .instantiate(
+                (deps.as_mut(), mock_env(), info),
+                vec![admin.to_string()],
+                true,
+                0,
+            )
+            .unwrap();
+
+        // a non-admin cannot update the role sets
+        let info = mock_info(proposer, &[]);
+        let err = contract
+            .update_proposers((deps.as_mut(), mock_env(), info), vec![proposer.to_string()])
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // the admin grants the proposer role
+        let info = mock_info(admin, &[]);
+        contract
+            .update_proposers((deps.as_mut(), mock_env(), info), vec![proposer.to_string()])
+            .unwrap();
+
+        let roles = contract.roles((deps.as_ref(), mock_env())).unwrap();
+        assert_eq!(roles.admins, vec![admin.to_string()]);
+        assert_eq!(roles.proposers, vec![proposer.to_string()]);
+        assert!(roles.executors.is_empty());
+
+        // the proposer may now schedule an operation
+        let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+            to_address: "bob".to_string(),
+            amount: coins(1, "utoken"),
+        }
+        .into()];
+        let salt = Binary(b"s".to_vec());
+        let info = mock_info(proposer, &[]);
+        contract
+            .schedule((deps.as_mut(), mock_env(), info), msgs.clone(), salt.clone(), 0)
+            .unwrap();
+
+        // with an empty executor set anyone may trigger the ready operation
+        let info = mock_info("anyone", &[]);
+        contract
+            .execute_scheduled((deps.as_mut(), mock_env(), info), msgs.clone(), salt.clone())
+            .unwrap();
+
+        // restrict executors, after which a stranger is rejected
+        let info = mock_info(admin, &[]);
+        contract
+            .update_executors((deps.as_mut(), mock_env(), info), vec![admin.to_string()])
+            .unwrap();
+        let info = mock_info(proposer, &[]);
+        contract
+            .schedule((deps.as_mut(), mock_env(), info), msgs.clone(), salt.clone(), 0)
+            .unwrap();
+        let info = mock_info("anyone", &[]);
+        let err = contract
+            .execute_scheduled((deps.as_mut(), mock_env(), info), msgs, salt)
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+
     mod msgs {
         use cosmwasm_std::{from_binary, from_slice, to_binary, BankMsg};
 
@@ -1,4 +1,7 @@
-use cosmwasm_std::{Addr, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    Addr, BankMsg, CosmosMsg, Deps, DepsMut, DistributionMsg, Env, MessageInfo, Response,
+    StakingMsg,
+};
 use cw1::{CanExecuteResp, Cw1};
 
 use crate::contract::Cw1WhitelistContract;
@@ -12,10 +15,71 @@ impl Cw1 for Cw1WhitelistContract<'_> {
         ctx: (DepsMut, Env, MessageInfo),
         msgs: Vec<CosmosMsg>,
     ) -> Result<Response, ContractError> {
-        let (deps, _, info) = ctx;
+        let (deps, env, info) = ctx;
 
-        if !self.is_admin(deps.as_ref(), &info.sender) {
-            return Err(ContractError::Unauthorized {});
+        if !self.is_proposer(deps.as_ref(), &info.sender) {
+            // Non-proposer senders spend against a live allowance for bank sends,
+            // and need the matching permission flag for staking/distribution
+            // messages. Everything else is rejected.
+            let mut allowance = self.allowances.may_load(deps.storage, &info.sender)?;
+            let permissions = self.permissions_of(deps.as_ref(), &info.sender)?;
+
+            for msg in &msgs {
+                match msg {
+                    CosmosMsg::Bank(BankMsg::Send { amount, .. }) => {
+                        let allowance =
+                            allowance.as_mut().ok_or(ContractError::NoAllowance {})?;
+                        // Expiry only disables bank spending, not separately
+                        // permissioned staking/distribution messages.
+                        if allowance.expires.is_expired(&env.block) {
+                            return Err(ContractError::NoAllowance {});
+                        }
+                        for coin in amount {
+                            let entry = allowance
+                                .balance
+                                .iter_mut()
+                                .find(|c| c.denom == coin.denom)
+                                .ok_or_else(|| ContractError::InsufficientAllowance {
+                                    denom: coin.denom.clone(),
+                                })?;
+                            entry.amount = entry.amount.checked_sub(coin.amount).map_err(|_| {
+                                ContractError::InsufficientAllowance {
+                                    denom: coin.denom.clone(),
+                                }
+                            })?;
+                        }
+                    }
+                    CosmosMsg::Staking(StakingMsg::Delegate { .. }) => {
+                        if !permissions.delegate {
+                            return Err(ContractError::Unauthorized {});
+                        }
+                    }
+                    CosmosMsg::Staking(StakingMsg::Redelegate { .. }) => {
+                        if !permissions.redelegate {
+                            return Err(ContractError::Unauthorized {});
+                        }
+                    }
+                    CosmosMsg::Staking(StakingMsg::Undelegate { .. }) => {
+                        if !permissions.undelegate {
+                            return Err(ContractError::Unauthorized {});
+                        }
+                    }
+                    CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward {
+                        ..
+                    }) => {
+                        if !permissions.withdraw {
+                            return Err(ContractError::Unauthorized {});
+                        }
+                    }
+                    _ => return Err(ContractError::Unauthorized {}),
+                }
+            }
+
+            if let Some(mut allowance) = allowance {
+                allowance.balance.retain(|c| !c.amount.is_zero());
+                self.allowances
+                    .save(deps.storage, &info.sender, &allowance)?;
+            }
         }
 
         let resp = Response::new()
@@ -28,14 +92,58 @@ impl Cw1 for Cw1WhitelistContract<'_> {
         &self,
         ctx: (Deps, Env),
         sender: String,
-        _msg: CosmosMsg,
+        msg: CosmosMsg,
     ) -> Result<cw1::CanExecuteResp, Self::Error> {
-        let (deps, _) = ctx;
+        let (deps, env) = ctx;
+        let sender = Addr::unchecked(&sender);
 
-        let resp = CanExecuteResp {
-            can_execute: self.is_admin(deps, &Addr::unchecked(&sender)),
+        let can_execute = if self.is_proposer(deps, &sender) {
+            true
+        } else {
+            // Mirror exactly the single-message authorization performed by
+            // `execute`, without mutating any allowance.
+            match msg {
+                CosmosMsg::Bank(BankMsg::Send { amount, .. }) => {
+                    match self.allowances.may_load(deps.storage, &sender)? {
+                        Some(mut allowance) if !allowance.expires.is_expired(&env.block) => {
+                            // Subtract cumulatively, exactly as `execute` does, so
+                            // duplicate denoms draw down the same balance entry.
+                            amount.iter().all(|coin| {
+                                match allowance
+                                    .balance
+                                    .iter_mut()
+                                    .find(|c| c.denom == coin.denom)
+                                {
+                                    Some(entry) => match entry.amount.checked_sub(coin.amount) {
+                                        Ok(remaining) => {
+                                            entry.amount = remaining;
+                                            true
+                                        }
+                                        Err(_) => false,
+                                    },
+                                    None => false,
+                                }
+                            })
+                        }
+                        _ => false,
+                    }
+                }
+                CosmosMsg::Staking(StakingMsg::Delegate { .. }) => {
+                    self.permissions_of(deps, &sender)?.delegate
+                }
+                CosmosMsg::Staking(StakingMsg::Redelegate { .. }) => {
+                    self.permissions_of(deps, &sender)?.redelegate
+                }
+                CosmosMsg::Staking(StakingMsg::Undelegate { .. }) => {
+                    self.permissions_of(deps, &sender)?.undelegate
+                }
+                CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward { .. }) => {
+                    self.permissions_of(deps, &sender)?.withdraw
+                }
+                _ => false,
+            }
         };
 
-        Ok(resp)
+        Ok(CanExecuteResp { can_execute })
     }
-}
\ No newline at end of file
+}
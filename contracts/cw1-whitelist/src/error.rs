@@ -0,0 +1,32 @@
+use cosmwasm_std::{StdError, Timestamp};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Contract is frozen")]
+    ContractFrozen {},
+
+    #[error("No allowance for this account")]
+    NoAllowance {},
+
+    #[error("Insufficient allowance for denom {denom}")]
+    InsufficientAllowance { denom: String },
+
+    #[error("Delay must be at least {min_delay} seconds")]
+    DelayTooShort { min_delay: u64 },
+
+    #[error("Operation is already scheduled")]
+    OperationAlreadyScheduled {},
+
+    #[error("Operation is not scheduled")]
+    OperationNotScheduled {},
+
+    #[error("Timelock has not expired, ready at {ready_at}")]
+    TimelockNotExpired { ready_at: Timestamp },
+}
@@ -0,0 +1,9 @@
+pub mod contract;
+pub mod cw1;
+pub mod error;
+pub mod responses;
+pub mod state;
+pub mod subkeys;
+
+#[cfg(test)]
+mod multitest;
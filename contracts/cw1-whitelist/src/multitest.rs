@@ -0,0 +1,345 @@
+use cosmwasm_std::{
+    coins, to_binary, Addr, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, WasmMsg,
+};
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_utils::Expiration;
+
+use crate::contract::{Cw1WhitelistContract, ExecMsg, InstantiateMsg, QueryMsg};
+use crate::error::ContractError;
+use crate::responses::{AdminListResponse, AllowanceResponse};
+use crate::state::Permissions;
+use cw1::CanExecuteResp;
+
+// cosmwasm entry points wired through the Sylvia-generated dispatchers, used to
+// register the contract code inside `cw_multi_test`.
+fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    msg.dispatch(&Cw1WhitelistContract::new(), (deps, env, info))
+}
+
+fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecMsg,
+) -> Result<Response, ContractError> {
+    msg.dispatch(&Cw1WhitelistContract::new(), (deps, env, info))
+}
+
+fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    msg.dispatch(&Cw1WhitelistContract::new(), (deps, env))
+}
+
+fn contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
+/// Thin proxy over a deployed `Cw1WhitelistContract`, so integration tests can
+/// drive real cross-contract dispatch through `cw_multi_test::App`. It wraps
+/// `execute`, `set_allowance` and `set_permissions` and the `allowance`,
+/// `can_execute` and `admin_list` queries; the other exec and query messages
+/// have no helper here.
+pub struct Cw1WhitelistContractProxy {
+    pub addr: Addr,
+}
+
+impl Cw1WhitelistContractProxy {
+    pub fn store_code(app: &mut App) -> u64 {
+        app.store_code(contract())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn instantiate(
+        app: &mut App,
+        code_id: u64,
+        sender: &Addr,
+        admins: Vec<String>,
+        mutable: bool,
+        min_delay: u64,
+        label: &str,
+    ) -> Result<Self, ContractError> {
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                sender.clone(),
+                &InstantiateMsg {
+                    admins,
+                    mutable,
+                    min_delay,
+                },
+                &[],
+                label,
+                None,
+            )
+            .map_err(|e| e.downcast().unwrap())?;
+        Ok(Self { addr })
+    }
+
+    pub fn execute(
+        &self,
+        app: &mut App,
+        sender: &Addr,
+        msgs: Vec<CosmosMsg>,
+    ) -> Result<cw_multi_test::AppResponse, ContractError> {
+        app.execute_contract(
+            sender.clone(),
+            self.addr.clone(),
+            &ExecMsg::Cw1(cw1::ExecMsg::Execute { msgs }),
+            &[],
+        )
+        // errors raised by dispatched messages (e.g. the staking module) are
+        // not `ContractError`s; they are carried as `ContractError::Std`
+        .map_err(|e| {
+            e.downcast()
+                .unwrap_or_else(|e| ContractError::Std(StdError::generic_err(e.to_string())))
+        })
+    }
+
+    pub fn set_allowance(
+        &self,
+        app: &mut App,
+        sender: &Addr,
+        spender: &str,
+        amount: Vec<Coin>,
+        expires: Option<Expiration>,
+    ) -> Result<cw_multi_test::AppResponse, ContractError> {
+        app.execute_contract(
+            sender.clone(),
+            self.addr.clone(),
+            &ExecMsg::Subkeys(crate::subkeys::ExecMsg::SetAllowance {
+                spender: spender.to_string(),
+                amount,
+                expires,
+            }),
+            &[],
+        )
+        .map_err(|e| e.downcast().unwrap())
+    }
+
+    pub fn set_permissions(
+        &self,
+        app: &mut App,
+        sender: &Addr,
+        spender: &str,
+        permissions: Permissions,
+    ) -> Result<cw_multi_test::AppResponse, ContractError> {
+        app.execute_contract(
+            sender.clone(),
+            self.addr.clone(),
+            &ExecMsg::Cw1WhitelistContract(crate::contract::ImplExecMsg::SetPermissions {
+                spender: spender.to_string(),
+                permissions,
+            }),
+            &[],
+        )
+        .map_err(|e| e.downcast().unwrap())
+    }
+
+    pub fn allowance(&self, app: &App, spender: &str) -> AllowanceResponse {
+        app.wrap()
+            .query_wasm_smart(
+                &self.addr,
+                &QueryMsg::Subkeys(crate::subkeys::QueryMsg::Allowance {
+                    spender: spender.to_string(),
+                }),
+            )
+            .unwrap()
+    }
+
+    pub fn can_execute(&self, app: &App, sender: &str, msg: CosmosMsg) -> CanExecuteResp {
+        app.wrap()
+            .query_wasm_smart(
+                &self.addr,
+                &QueryMsg::Cw1(cw1::QueryMsg::CanExecute {
+                    sender: sender.to_string(),
+                    msg,
+                }),
+            )
+            .unwrap()
+    }
+
+    pub fn admin_list(&self, app: &App) -> AdminListResponse {
+        app.wrap()
+            .query_wasm_smart(
+                &self.addr,
+                &QueryMsg::Cw1WhitelistContract(crate::contract::ImplQueryMsg::AdminList {}),
+            )
+            .unwrap()
+    }
+}
+
+fn mock_app(init: &[(Addr, Vec<Coin>)]) -> App {
+    App::new(|router, _, storage| {
+        for (addr, coins) in init {
+            router.bank.init_balance(storage, addr, coins.clone()).unwrap();
+        }
+    })
+}
+
+#[test]
+fn allowance_depletes_across_real_bank_transfer() {
+    let admin = Addr::unchecked("admin");
+    let spender = Addr::unchecked("spender");
+    let recipient = Addr::unchecked("recipient");
+
+    let mut app = mock_app(&[(admin.clone(), coins(1000, "utoken"))]);
+    let code_id = Cw1WhitelistContractProxy::store_code(&mut app);
+    let contract = Cw1WhitelistContractProxy::instantiate(
+        &mut app,
+        code_id,
+        &admin,
+        vec![admin.to_string()],
+        true,
+        0,
+        "cw1-whitelist",
+    )
+    .unwrap();
+
+    // fund the contract so it can actually forward a bank transfer
+    app.send_tokens(admin.clone(), contract.addr.clone(), &coins(1000, "utoken"))
+        .unwrap();
+
+    // grant the spender a bounded allowance
+    contract
+        .set_allowance(&mut app, &admin, spender.as_str(), coins(600, "utoken"), None)
+        .unwrap();
+
+    // the spender dispatches a real bank send through the contract
+    let send: CosmosMsg = cosmwasm_std::BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: coins(400, "utoken"),
+    }
+    .into();
+    contract
+        .execute(&mut app, &spender, vec![send.clone()])
+        .unwrap();
+
+    // the transfer really happened and the allowance shrank accordingly
+    let balance = app.wrap().query_balance(&recipient, "utoken").unwrap();
+    assert_eq!(balance.amount.u128(), 400);
+    let allowance = contract.allowance(&app, spender.as_str()).allowance.unwrap();
+    assert_eq!(allowance.balance, coins(200, "utoken"));
+
+    // spending past the remaining allowance is rejected on the dispatched path
+    let err = contract
+        .execute(&mut app, &spender, vec![send])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientAllowance {
+            denom: "utoken".to_string()
+        }
+    );
+}
+
+#[test]
+fn staking_permission_enforced_on_real_message() {
+    let admin = Addr::unchecked("admin");
+    let spender = Addr::unchecked("spender");
+
+    let mut app = mock_app(&[]);
+    let code_id = Cw1WhitelistContractProxy::store_code(&mut app);
+    let contract = Cw1WhitelistContractProxy::instantiate(
+        &mut app,
+        code_id,
+        &admin,
+        vec![admin.to_string()],
+        true,
+        0,
+        "cw1-whitelist",
+    )
+    .unwrap();
+
+    let delegate: CosmosMsg = cosmwasm_std::StakingMsg::Delegate {
+        validator: "valoper".to_string(),
+        amount: Coin::new(100, "ustake"),
+    }
+    .into();
+
+    // without the permission the contract refuses before any dispatch
+    assert!(
+        !contract
+            .can_execute(&app, spender.as_str(), delegate.clone())
+            .can_execute
+    );
+    let err = contract
+        .execute(&mut app, &spender, vec![delegate.clone()])
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // once granted, authorization passes (the staking module then rejects the
+    // unknown validator, proving the message was actually dispatched)
+    contract
+        .set_permissions(
+            &mut app,
+            &admin,
+            spender.as_str(),
+            Permissions {
+                delegate: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert!(
+        contract
+            .can_execute(&app, spender.as_str(), delegate.clone())
+            .can_execute
+    );
+    let err = contract
+        .execute(&mut app, &spender, vec![delegate])
+        .unwrap_err();
+    // no longer an authorization error from our contract
+    assert_ne!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn forwarded_wasm_execute_runs_on_second_instance() {
+    let admin = Addr::unchecked("admin");
+
+    let mut app = mock_app(&[]);
+    let code_id = Cw1WhitelistContractProxy::store_code(&mut app);
+
+    let a = Cw1WhitelistContractProxy::instantiate(
+        &mut app,
+        code_id,
+        &admin,
+        vec![admin.to_string()],
+        true,
+        0,
+        "a",
+    )
+    .unwrap();
+    // the second instance trusts the first one as its admin
+    let b = Cw1WhitelistContractProxy::instantiate(
+        &mut app,
+        code_id,
+        &admin,
+        vec![a.addr.to_string()],
+        true,
+        0,
+        "b",
+    )
+    .unwrap();
+
+    assert!(b.admin_list(&app).mutable);
+
+    // forward a WasmMsg::Execute through `a` that freezes `b`
+    let freeze: CosmosMsg = WasmMsg::Execute {
+        contract_addr: b.addr.to_string(),
+        msg: to_binary(&ExecMsg::Cw1WhitelistContract(
+            crate::contract::ImplExecMsg::Freeze {},
+        ))
+        .unwrap(),
+        funds: vec![],
+    }
+    .into();
+    a.execute(&mut app, &admin, vec![freeze]).unwrap();
+
+    // the forwarded call really ran: `b` is now frozen
+    assert!(!b.admin_list(&app).mutable);
+}
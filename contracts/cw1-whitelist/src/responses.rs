@@ -0,0 +1,48 @@
+use cosmwasm_schema::cw_serde;
+
+use crate::state::{Allowance, Permissions};
+
+#[cw_serde]
+pub struct AdminListResponse {
+    pub admins: Vec<String>,
+    pub mutable: bool,
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    pub admins: Vec<String>,
+    pub proposers: Vec<String>,
+    pub executors: Vec<String>,
+}
+
+#[cw_serde]
+pub struct AllowanceResponse {
+    pub allowance: Option<Allowance>,
+}
+
+#[cw_serde]
+pub struct AllowanceInfo {
+    pub spender: String,
+    pub allowance: Allowance,
+}
+
+#[cw_serde]
+pub struct AllAllowancesResponse {
+    pub allowances: Vec<AllowanceInfo>,
+}
+
+#[cw_serde]
+pub struct PermissionsResponse {
+    pub permissions: Permissions,
+}
+
+#[cw_serde]
+pub struct PermissionsInfo {
+    pub spender: String,
+    pub permissions: Permissions,
+}
+
+#[cw_serde]
+pub struct AllPermissionsResponse {
+    pub permissions: Vec<PermissionsInfo>,
+}
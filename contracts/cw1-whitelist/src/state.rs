@@ -0,0 +1,22 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Coin;
+use cw_utils::Expiration;
+
+/// Bounded spending rights granted to a non-admin address.
+#[cw_serde]
+pub struct Allowance {
+    /// Coins the spender is still allowed to send.
+    pub balance: Vec<Coin>,
+    /// When the allowance stops being valid.
+    pub expires: Expiration,
+}
+
+/// Message categories a non-admin spender is allowed to dispatch.
+#[cw_serde]
+#[derive(Default)]
+pub struct Permissions {
+    pub delegate: bool,
+    pub redelegate: bool,
+    pub undelegate: bool,
+    pub withdraw: bool,
+}
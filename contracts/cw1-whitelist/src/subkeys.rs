@@ -0,0 +1,207 @@
+use cosmwasm_std::{
+    Coin, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError,
+};
+use cw_storage_plus::Bound;
+use cw_utils::Expiration;
+use sylvia::interface;
+
+use crate::contract::{
+    add_coin, sub_coin_saturating, Cw1WhitelistContract, DEFAULT_LIMIT, MAX_LIMIT,
+};
+use crate::error::ContractError;
+use crate::responses::{AllAllowancesResponse, AllowanceInfo, AllowanceResponse};
+use crate::state::Allowance;
+
+/// Subkey capability layered on top of the plain whitelist: admins grant
+/// non-admin addresses bounded, optionally time-limited spending rights that
+/// `Cw1::execute` draws down on each `BankMsg::Send`.
+#[interface]
+pub trait Subkeys {
+    type Error: From<StdError>;
+
+    #[msg(exec)]
+    fn increase_allowance(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        spender: String,
+        amount: Coin,
+        expires: Option<Expiration>,
+    ) -> Result<Response, Self::Error>;
+
+    #[msg(exec)]
+    fn decrease_allowance(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        spender: String,
+        amount: Coin,
+        expires: Option<Expiration>,
+    ) -> Result<Response, Self::Error>;
+
+    #[msg(exec)]
+    fn set_allowance(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        spender: String,
+        amount: Vec<Coin>,
+        expires: Option<Expiration>,
+    ) -> Result<Response, Self::Error>;
+
+    #[msg(query)]
+    fn allowance(&self, ctx: (Deps, Env), spender: String)
+        -> Result<AllowanceResponse, Self::Error>;
+
+    #[msg(query)]
+    fn all_allowances(
+        &self,
+        ctx: (Deps, Env),
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<AllAllowancesResponse, Self::Error>;
+}
+
+impl Subkeys for Cw1WhitelistContract<'_> {
+    type Error = ContractError;
+
+    fn increase_allowance(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        spender: String,
+        amount: Coin,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        let (deps, _, info) = ctx;
+
+        if !self.is_admin(deps.as_ref(), &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        let spender_addr = deps.api.addr_validate(&spender)?;
+        let mut allowance = self
+            .allowances
+            .may_load(deps.storage, &spender_addr)?
+            .unwrap_or_else(|| Allowance {
+                balance: vec![],
+                expires: Expiration::Never {},
+            });
+
+        add_coin(&mut allowance.balance, amount)?;
+        if let Some(expires) = expires {
+            allowance.expires = expires;
+        }
+        self.allowances
+            .save(deps.storage, &spender_addr, &allowance)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "increase_allowance")
+            .add_attribute("spender", spender);
+        Ok(resp)
+    }
+
+    fn decrease_allowance(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        spender: String,
+        amount: Coin,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        let (deps, _, info) = ctx;
+
+        if !self.is_admin(deps.as_ref(), &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        let spender_addr = deps.api.addr_validate(&spender)?;
+        let mut allowance = self
+            .allowances
+            .may_load(deps.storage, &spender_addr)?
+            .ok_or(ContractError::NoAllowance {})?;
+
+        // Shrinking below zero just drops the denom entirely.
+        sub_coin_saturating(&mut allowance.balance, &amount);
+        if let Some(expires) = expires {
+            allowance.expires = expires;
+        }
+
+        if allowance.balance.is_empty() {
+            self.allowances.remove(deps.storage, &spender_addr);
+        } else {
+            self.allowances
+                .save(deps.storage, &spender_addr, &allowance)?;
+        }
+
+        let resp = Response::new()
+            .add_attribute("action", "decrease_allowance")
+            .add_attribute("spender", spender);
+        Ok(resp)
+    }
+
+    fn set_allowance(
+        &self,
+        ctx: (DepsMut, Env, MessageInfo),
+        spender: String,
+        amount: Vec<Coin>,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        let (deps, _, info) = ctx;
+
+        if !self.is_admin(deps.as_ref(), &info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        let spender_addr = deps.api.addr_validate(&spender)?;
+        let allowance = Allowance {
+            balance: amount,
+            expires: expires.unwrap_or(Expiration::Never {}),
+        };
+        self.allowances
+            .save(deps.storage, &spender_addr, &allowance)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "set_allowance")
+            .add_attribute("spender", spender);
+        Ok(resp)
+    }
+
+    fn allowance(
+        &self,
+        ctx: (Deps, Env),
+        spender: String,
+    ) -> Result<AllowanceResponse, ContractError> {
+        let (deps, _) = ctx;
+        let spender_addr = deps.api.addr_validate(&spender)?;
+        let allowance = self.allowances.may_load(deps.storage, &spender_addr)?;
+        Ok(AllowanceResponse { allowance })
+    }
+
+    fn all_allowances(
+        &self,
+        ctx: (Deps, Env),
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<AllAllowancesResponse, ContractError> {
+        let (deps, _) = ctx;
+
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after
+            .map(|s| deps.api.addr_validate(&s))
+            .transpose()?;
+        let start = start.as_ref().map(Bound::exclusive);
+
+        let allowances: Result<Vec<_>, ContractError> = self
+            .allowances
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (spender, allowance) = item?;
+                Ok(AllowanceInfo {
+                    spender: spender.into(),
+                    allowance,
+                })
+            })
+            .collect();
+
+        Ok(AllAllowancesResponse {
+            allowances: allowances?,
+        })
+    }
+}